@@ -4,146 +4,334 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, AttrStyle, Attribute, DeriveInput, Ident, Path};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, AttrStyle, Attribute, DeriveInput, Ident, LitInt, Token};
+
+/// A single `repr` hint, e.g. the `packed(2)` in `#[repr(C, packed(2))]`.
+///
+/// A type can carry more than one of these, spread across a single `#[repr(...)]` with several
+/// comma-separated hints, or across several separate `#[repr(...)]` attributes. See
+/// [`collect_reprs`].
+enum ReprFlavor {
+    C,
+    Transparent,
+    Packed(Option<u32>),
+    Align(u32),
+    Primitive(Ident),
+}
+
+impl Parse for ReprFlavor {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "C" => Ok(ReprFlavor::C),
+            "transparent" => Ok(ReprFlavor::Transparent),
+            "packed" => {
+                if input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let n: LitInt = content.parse()?;
+                    Ok(ReprFlavor::Packed(Some(n.base10_parse()?)))
+                } else {
+                    Ok(ReprFlavor::Packed(None))
+                }
+            }
+            "align" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let n: LitInt = content.parse()?;
+                Ok(ReprFlavor::Align(n.base10_parse()?))
+            }
+            name if name.starts_with('u') || name.starts_with('i') => {
+                Ok(ReprFlavor::Primitive(ident))
+            }
+            _ => Err(syn::Error::new_spanned(&ident, "unrecognized repr hint")),
+        }
+    }
+}
+
+/// Parses every comma-separated hint in a single `#[repr(...)]` attribute body.
+///
+/// Each hint is parsed independently: one unrecognized or not-yet-supported hint (e.g. `Rust`,
+/// or some future repr this crate doesn't know about) is skipped rather than failing the whole
+/// group, so it can't mask the hints around it.
+fn parse_repr_hints(input: ParseStream) -> syn::Result<Vec<ReprFlavor>> {
+    let mut reprs = Vec::new();
+
+    while !input.is_empty() {
+        match input.parse::<ReprFlavor>() {
+            Ok(flavor) => reprs.push(flavor),
+            // Skip whatever is left of the unrecognized hint (e.g. its arguments), up to the next
+            // comma
+            Err(_) => input.step(|cursor| {
+                let mut rest = *cursor;
+                while !matches!(rest.punct(), Some((p, _)) if p.as_char() == ',') {
+                    match rest.token_tree() {
+                        Some((_, next)) => rest = next,
+                        None => break,
+                    }
+                }
+                Ok(((), rest))
+            })?,
+        }
+
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+
+    Ok(reprs)
+}
+
+/// Collects every `repr` hint out of a type's attributes, regardless of whether they're spread
+/// across multiple `#[repr(...)]` attributes or grouped into one.
+fn collect_reprs(attrs: &[Attribute]) -> Vec<ReprFlavor> {
+    let mut reprs = Vec::new();
+
+    for attr in attrs {
+        // If the style isn't outer, reject it
+        if !matches!(attr.style, AttrStyle::Outer) {
+            continue;
+        }
+
+        // If the path doesn't match, reject it
+        if !attr.path.is_ident("repr") {
+            continue;
+        }
+
+        if let Ok(parsed) = attr.parse_args_with(parse_repr_hints) {
+            reprs.extend(parsed);
+        }
+    }
+
+    reprs
+}
 
 macro_rules! repr_derive {
-    ($tr:ident : $fn:ident($inner:expr) ) => {
+    ($tr:ident : $fn:ident($name:literal, $pred:expr) ) => {
         #[proc_macro_derive($tr)]
         pub fn $fn(input: TokenStream) -> TokenStream {
             let input = parse_macro_input!(input as DeriveInput);
 
             let ident = input.ident;
 
-            if has_repr(&input.attrs, $inner) {
+            if collect_reprs(&input.attrs).iter().any($pred) {
+                let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
                 quote!(
-                    unsafe impl $tr for #ident {}
+                    unsafe impl #impl_generics $tr for #ident #ty_generics #where_clause {}
                 ).into()
             } else {
-                panic!("Can't derive {} on a struct without #[repr({})]", stringify!($tr), $inner);
+                syn::Error::new_spanned(
+                    &ident,
+                    format!("expected #[repr({})] on this type", $name),
+                )
+                .to_compile_error()
+                .into()
             }
         }
     }
 }
 
-repr_derive!(Packed: repr_packed("packed"));
-repr_derive!(Transparent: repr_transparent("transparent"));
-repr_derive!(C: repr_c("C"));
+repr_derive!(Transparent: repr_transparent("transparent", |f| matches!(f, ReprFlavor::Transparent)));
+repr_derive!(C: repr_c("C", |f| matches!(f, ReprFlavor::C)));
+
+#[proc_macro_derive(Packed)]
+pub fn repr_packed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident;
+
+    let packing = collect_reprs(&input.attrs).into_iter().find_map(|f| match f {
+        ReprFlavor::Packed(n) => Some(n.unwrap_or(1) as usize),
+        _ => None,
+    });
+
+    if let Some(n) = packing {
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+        quote!(
+            unsafe impl #impl_generics Packed<#n> for #ident #ty_generics #where_clause {}
+        )
+        .into()
+    } else {
+        syn::Error::new_spanned(&ident, "expected #[repr(packed)] on this type")
+            .to_compile_error()
+            .into()
+    }
+}
+
+#[proc_macro_derive(Aligned)]
+pub fn repr_aligned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident;
+
+    let alignment = collect_reprs(&input.attrs).into_iter().find_map(|f| match f {
+        ReprFlavor::Align(n) => Some(n as usize),
+        _ => None,
+    });
+
+    if let Some(n) = alignment {
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+        quote!(
+            unsafe impl #impl_generics Aligned<#n> for #ident #ty_generics #where_clause {}
+        )
+        .into()
+    } else {
+        syn::Error::new_spanned(&ident, "expected #[repr(align(N))] on this type")
+            .to_compile_error()
+            .into()
+    }
+}
 
 #[proc_macro_derive(PrimitiveRepr)]
 pub fn primitive_repr(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let data_enum = match &input.data {
-        syn::Data::Struct(_) => panic!("Can't derive PrimitiveRepr on a struct"),
+        syn::Data::Struct(_) => {
+            return syn::Error::new_spanned(&input.ident, "can't derive PrimitiveRepr on a struct")
+                .to_compile_error()
+                .into();
+        }
         syn::Data::Enum(data_enum) => data_enum,
-        syn::Data::Union(_) => panic!("Can't derive PrimitiveRepr on an union"),
+        syn::Data::Union(_) => {
+            return syn::Error::new_spanned(&input.ident, "can't derive PrimitiveRepr on a union")
+                .to_compile_error()
+                .into();
+        }
     };
 
     if data_enum.variants.is_empty() {
-        panic!("Can't derive PrimitiveRepr on a zero variant enum");
+        return syn::Error::new_spanned(
+            &data_enum.variants,
+            "can't derive PrimitiveRepr on a zero variant enum",
+        )
+        .to_compile_error()
+        .into();
     }
 
     if let Some(type_name) = find_repr_type(&input.attrs) {
         let ident = input.ident;
         let repr_ident = Ident::new(&type_name, ident.span());
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
         quote!(
-            unsafe impl PrimitiveRepr for #ident {
+            unsafe impl #impl_generics PrimitiveRepr for #ident #ty_generics #where_clause {
                 type Type = #repr_ident;
             }
         )
         .into()
     } else {
-        panic!("Can't derive PrimitiveRepr on a struct without repr(u*) or repr(i*)");
+        syn::Error::new_spanned(
+            &input.ident,
+            "expected #[repr(u*)] or #[repr(i*)] on this type",
+        )
+        .to_compile_error()
+        .into()
     }
 }
 
-fn find_repr_type(attributes: &[Attribute]) -> Option<String> {
-    for attr in attributes {
-        // If the style isn't outer, reject it
-        if !matches!(attr.style, AttrStyle::Outer) {
-            continue;
+#[proc_macro_derive(TryFromPrimitive)]
+pub fn try_from_primitive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let data_enum = match &input.data {
+        syn::Data::Struct(_) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "can't derive TryFromPrimitive on a struct",
+            )
+            .to_compile_error()
+            .into();
         }
-        // If the path doesn't match, reject it
-        if let Path {
-            leading_colon: None,
-            ref segments,
-        } = attr.path
-        {
-            // If there's more than one, reject it
-            if segments.len() != 1 {
-                continue;
-            }
-
-            let seg = segments.first().unwrap();
-
-            // If there are arguments, reject it
-            if !seg.arguments.is_empty() {
-                continue;
-            }
-
-            // If the ident isn't "repr", reject it
-            if seg.ident != "repr" {
-                continue;
-            }
-        } else {
-            // If we don't match, reject if
-            continue;
+        syn::Data::Enum(data_enum) => data_enum,
+        syn::Data::Union(_) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "can't derive TryFromPrimitive on a union",
+            )
+            .to_compile_error()
+            .into();
         }
+    };
 
-        let mut repr_type_name = format!("{}", attr.tokens);
+    if data_enum.variants.is_empty() {
+        return syn::Error::new_spanned(
+            &data_enum.variants,
+            "can't derive TryFromPrimitive on a zero variant enum",
+        )
+        .to_compile_error()
+        .into();
+    }
 
-        // Ensure repr is (u*) or (i*) and return what's inside.
-        if (repr_type_name.starts_with("(u") || repr_type_name.starts_with("(i"))
-            && repr_type_name.ends_with(')')
-        {
-            repr_type_name = repr_type_name[1..repr_type_name.len() - 1].to_string();
-            return Some(repr_type_name);
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return syn::Error::new_spanned(
+                variant,
+                "can't derive TryFromPrimitive on an enum with variants that have fields",
+            )
+            .to_compile_error()
+            .into();
         }
     }
-    None
-}
 
-fn has_repr(attrs: &[Attribute], repr: &str) -> bool {
-    for attr in attrs {
-        // If the style isn't outer, reject it
-        if !matches!(attr.style, AttrStyle::Outer) {
-            continue;
+    let type_name = match find_repr_type(&input.attrs) {
+        Some(type_name) => type_name,
+        None => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "expected #[repr(u*)] or #[repr(i*)] on this type",
+            )
+            .to_compile_error()
+            .into();
         }
+    };
 
-        // If the path doesn't match, reject it
-        if let Path {
-            leading_colon: None,
-            ref segments,
-        } = attr.path
-        {
-            // If there's more than one, reject it
-            if segments.len() != 1 {
-                continue;
-            }
+    let ident = input.ident;
+    let repr_ident = Ident::new(&type_name, ident.span());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-            let seg = segments.first().unwrap();
+    let mut consts = Vec::new();
+    let mut arms = Vec::new();
 
-            // If there are arguments, reject it
-            if !seg.arguments.is_empty() {
-                continue;
-            }
+    for variant in &data_enum.variants {
+        let variant_ident = &variant.ident;
+        let const_ident = Ident::new(
+            &format!("__{}_DISCRIMINANT", variant_ident.to_string().to_uppercase()),
+            variant_ident.span(),
+        );
 
-            // If the ident isn't "repr", reject it
-            if seg.ident != "repr" {
-                continue;
-            }
-        } else {
-            // If we don't match, reject if
-            continue;
-        }
+        consts.push(quote!(
+            const #const_ident: #repr_ident = #ident::#variant_ident as #repr_ident;
+        ));
+        arms.push(quote!(
+            #const_ident => Ok(#ident::#variant_ident),
+        ));
+    }
 
-        // If it doesn't match, reject it
+    quote!(
+        impl #impl_generics core::convert::TryFrom<<#ident #ty_generics as ::repr_trait::PrimitiveRepr>::Type> for #ident #ty_generics #where_clause {
+            type Error = ::repr_trait::TryFromPrimitiveError<<#ident #ty_generics as ::repr_trait::PrimitiveRepr>::Type>;
 
-        if format!("{}", attr.tokens) != format!("({})", repr) {
-            continue;
+            fn try_from(value: <#ident #ty_generics as ::repr_trait::PrimitiveRepr>::Type) -> Result<Self, Self::Error> {
+                #(#consts)*
+
+                match value {
+                    #(#arms)*
+                    _ => Err(::repr_trait::TryFromPrimitiveError { value }),
+                }
+            }
         }
 
-        return true;
-    }
+        impl #impl_generics ::repr_trait::TryFromPrimitive for #ident #ty_generics #where_clause {}
+    )
+    .into()
+}
 
-    false
+/// Finds the `u*`/`i*` primitive type named by a `#[repr(...)]` attribute, if any.
+fn find_repr_type(attributes: &[Attribute]) -> Option<String> {
+    collect_reprs(attributes).into_iter().find_map(|f| match f {
+        ReprFlavor::Primitive(ident) => Some(ident.to_string()),
+        _ => None,
+    })
 }
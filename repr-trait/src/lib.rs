@@ -72,9 +72,45 @@ macro_rules! trait_and_docs {
 }
 
 trait_and_docs!(C as "C");
-trait_and_docs!(Packed as "packed");
 trait_and_docs!(Transparent as "transparent");
 
+/// Trait for types declared with `#[repr(packed)]` or `#[repr(packed(N))]`.
+///
+/// The const parameter `N` is the packing alignment; it defaults to `1`, matching a bare
+/// `#[repr(packed)]`. A type declared with `#[repr(packed(N))]` implements `Packed<N>`
+/// specifically, so an `unsafe fn` that is only sound at a particular packing can require it
+/// with a bound like `T: Packed<4>`.
+///
+/// # Safety
+///
+/// This trait should only be implemented for types with the correct `repr`. Because `repr`s
+/// cannot be checked by the compiler, this trait is `unsafe`.
+///
+/// Use the corresponding derive macro to safely derive this on any type with the correct
+/// `repr`.
+pub unsafe trait Packed<const N: usize = 1> {}
+
+/// Derive macro for [`Packed`](trait@Packed)
+///
+/// Can be added to any type with the correct
+pub use repr_trait_derive::Packed;
+
+/// Trait for types declared with `#[repr(align(N))]`.
+///
+/// # Safety
+///
+/// This trait should only be implemented for types with the correct `repr`. Because `repr`s
+/// cannot be checked by the compiler, this trait is `unsafe`.
+///
+/// Use the corresponding derive macro to safely derive this on any type with the correct
+/// `repr`.
+pub unsafe trait Aligned<const N: usize> {}
+
+/// Derive macro for [`Aligned`](trait@Aligned)
+///
+/// Can be added to any type with the correct
+pub use repr_trait_derive::Aligned;
+
 /// Trait for types declared with #[repr(u*)] or #[repr(i*)].
 ///
 /// # Safety
@@ -102,10 +138,46 @@ pub fn discriminant<T: PrimitiveRepr>(enum_value: &T) -> &T::Type {
 /// Derive macro for [`PrimitiveRepr`](trait@PrimitiveRepr)
 pub use repr_trait_derive::PrimitiveRepr;
 
+/// Trait for [`PrimitiveRepr`] enums that can be rebuilt from their discriminant value.
+///
+/// This is the reverse direction of [`discriminant`]: given a `T::Type`, produce the `T`
+/// variant with that discriminant, if one exists.
+///
+/// Use the corresponding derive macro to implement this trait along with
+/// [`TryFrom`](core::convert::TryFrom)`<T::Type>` for any fieldless, [`PrimitiveRepr`] enum.
+pub trait TryFromPrimitive: PrimitiveRepr + Sized {}
+
+/// Derive macro for [`TryFromPrimitive`](trait@TryFromPrimitive)
+///
+/// In addition to implementing `TryFromPrimitive`, this derive implements
+/// `TryFrom<<Self as PrimitiveRepr>::Type>` for the annotated enum, returning
+/// [`TryFromPrimitiveError`] when the value doesn't match any variant's discriminant.
+pub use repr_trait_derive::TryFromPrimitive;
+
+/// Error returned when a value doesn't match any discriminant of a [`TryFromPrimitive`] enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromPrimitiveError<T> {
+    /// The value that didn't match any discriminant.
+    pub value: T,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for TryFromPrimitiveError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} does not match any discriminant", self.value)
+    }
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> std::error::Error for TryFromPrimitiveError<T> {}
+
 #[cfg(test)]
 mod test {
     // Due to https://github.com/dtolnay/trybuild/issues/58, all trybuild tests must
     // run in the same test case.
+    //
+    // Any derive behavior change needs a fixture added here in the same commit: a
+    // `t.compile_fail`/`t.pass` line with no matching `test/*.rs` file fails silently (trybuild
+    // reports "file not found" as a test failure, not a build error), so `cargo test` must
+    // actually be run, not just `cargo build`, to notice a missing fixture.
     #[test]
     fn test_compilation() {
         let t = trybuild::TestCases::new();
@@ -121,5 +193,21 @@ mod test {
         t.compile_fail("test/primitive_repr_fail.rs");
 
         t.compile_fail("test/zero_variants_fail.rs");
+
+        t.compile_fail("test/aligned_fail.rs");
+        t.pass("test/aligned_pass.rs");
+
+        t.compile_fail("test/try_from_primitive_fail.rs");
+        t.compile_fail("test/try_from_primitive_fields_fail.rs");
+        t.pass("test/try_from_primitive_pass.rs");
+
+        t.pass("test/generic_c_pass.rs");
+        t.pass("test/generic_packed_pass.rs");
+        t.pass("test/generic_aligned_pass.rs");
+
+        t.pass("test/multi_hint_pass.rs");
+        t.pass("test/split_attrs_pass.rs");
+        t.pass("test/unrecognized_hint_pass.rs");
+        t.pass("test/packed_n_pass.rs");
     }
 }
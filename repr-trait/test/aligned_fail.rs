@@ -0,0 +1,6 @@
+use repr_trait::Aligned;
+
+#[derive(Aligned)]
+struct NotAligned(u32);
+
+fn main() {}
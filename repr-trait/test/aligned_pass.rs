@@ -0,0 +1,11 @@
+use repr_trait::Aligned;
+
+#[derive(Aligned)]
+#[repr(align(8))]
+struct AlignedStruct(u32);
+
+fn assert_aligned<T: Aligned<8>>() {}
+
+fn main() {
+    assert_aligned::<AlignedStruct>();
+}
@@ -0,0 +1,9 @@
+use repr_trait::C;
+
+#[derive(C)]
+struct NotC {
+    a: u32,
+    b: u8,
+}
+
+fn main() {}
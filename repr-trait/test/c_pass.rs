@@ -0,0 +1,14 @@
+use repr_trait::C;
+
+#[derive(C)]
+#[repr(C)]
+struct CStruct {
+    a: u32,
+    b: u8,
+}
+
+fn assert_c<T: C>() {}
+
+fn main() {
+    assert_c::<CStruct>();
+}
@@ -0,0 +1,14 @@
+use repr_trait::C;
+
+#[derive(C)]
+#[repr(C)]
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn assert_c<T: C>() {}
+
+fn main() {
+    assert_c::<Pair<u32>>();
+}
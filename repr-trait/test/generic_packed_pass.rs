@@ -0,0 +1,11 @@
+use repr_trait::Packed;
+
+#[derive(Packed)]
+#[repr(packed)]
+struct Wrapper<T>(T);
+
+fn assert_packed<T: Packed>() {}
+
+fn main() {
+    assert_packed::<Wrapper<u32>>();
+}
@@ -0,0 +1,13 @@
+use repr_trait::{Packed, C};
+
+#[derive(C, Packed)]
+#[repr(C, packed)]
+struct CombinedStruct(u32, u8);
+
+fn assert_c<T: C>() {}
+fn assert_packed<T: Packed>() {}
+
+fn main() {
+    assert_c::<CombinedStruct>();
+    assert_packed::<CombinedStruct>();
+}
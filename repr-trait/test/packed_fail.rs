@@ -0,0 +1,6 @@
+use repr_trait::Packed;
+
+#[derive(Packed)]
+struct NotPacked(u32, u8);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use repr_trait::Packed;
+
+#[derive(Packed)]
+#[repr(packed(2))]
+struct PackedToTwo(u32, u8);
+
+fn assert_packed<T: Packed<2>>() {}
+
+fn main() {
+    assert_packed::<PackedToTwo>();
+}
@@ -0,0 +1,11 @@
+use repr_trait::Packed;
+
+#[derive(Packed)]
+#[repr(packed)]
+struct PackedStruct(u32, u8);
+
+fn assert_packed<T: Packed>() {}
+
+fn main() {
+    assert_packed::<PackedStruct>();
+}
@@ -0,0 +1,9 @@
+use repr_trait::PrimitiveRepr;
+
+#[derive(PrimitiveRepr)]
+enum NotPrimitiveRepr {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use repr_trait::{Aligned, C};
+
+#[derive(C, Aligned)]
+#[repr(C)]
+#[repr(align(4))]
+struct SplitStruct(u32);
+
+fn assert_c<T: C>() {}
+fn assert_aligned<T: Aligned<4>>() {}
+
+fn main() {
+    assert_c::<SplitStruct>();
+    assert_aligned::<SplitStruct>();
+}
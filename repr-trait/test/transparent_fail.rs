@@ -0,0 +1,6 @@
+use repr_trait::Transparent;
+
+#[derive(Transparent)]
+struct NotTransparent(u32);
+
+fn main() {}
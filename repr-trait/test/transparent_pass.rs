@@ -0,0 +1,11 @@
+use repr_trait::Transparent;
+
+#[derive(Transparent)]
+#[repr(transparent)]
+struct TransparentStruct(u32);
+
+fn assert_transparent<T: Transparent>() {}
+
+fn main() {
+    assert_transparent::<TransparentStruct>();
+}
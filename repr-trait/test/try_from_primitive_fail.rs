@@ -0,0 +1,9 @@
+use repr_trait::TryFromPrimitive;
+
+#[derive(TryFromPrimitive)]
+enum NotReprPrimitive {
+    A,
+    B,
+}
+
+fn main() {}
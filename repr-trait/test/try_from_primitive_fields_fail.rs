@@ -0,0 +1,10 @@
+use repr_trait::{PrimitiveRepr, TryFromPrimitive};
+
+#[derive(PrimitiveRepr, TryFromPrimitive)]
+#[repr(u8)]
+enum HasFields {
+    A,
+    B(u32),
+}
+
+fn main() {}
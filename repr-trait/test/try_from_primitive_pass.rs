@@ -0,0 +1,13 @@
+use repr_trait::{PrimitiveRepr, TryFromPrimitive};
+use std::convert::TryFrom;
+
+#[derive(PrimitiveRepr, TryFromPrimitive)]
+#[repr(u8)]
+enum Simple {
+    A,
+    B,
+}
+
+fn main() {
+    assert!(Simple::try_from(0u8).is_ok());
+}
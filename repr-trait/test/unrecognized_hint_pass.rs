@@ -0,0 +1,13 @@
+use repr_trait::Aligned;
+
+// `Rust` isn't a hint this crate recognizes; it shouldn't take the `align(4)` hint next to it
+// down with it.
+#[derive(Aligned)]
+#[repr(Rust, align(4))]
+struct AlignedWithUnknownHint(u32);
+
+fn assert_aligned<T: Aligned<4>>() {}
+
+fn main() {
+    assert_aligned::<AlignedWithUnknownHint>();
+}
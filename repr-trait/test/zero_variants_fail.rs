@@ -0,0 +1,7 @@
+use repr_trait::PrimitiveRepr;
+
+#[derive(PrimitiveRepr)]
+#[repr(u8)]
+enum NoVariants {}
+
+fn main() {}
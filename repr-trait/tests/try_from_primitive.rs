@@ -0,0 +1,39 @@
+use repr_trait::{PrimitiveRepr, TryFromPrimitive};
+use std::convert::TryFrom;
+
+#[derive(PrimitiveRepr, TryFromPrimitive, Debug, PartialEq)]
+#[repr(i64)]
+enum SimpleEnum {
+    A,
+    B,
+    C,
+}
+
+#[derive(PrimitiveRepr, TryFromPrimitive, Debug, PartialEq)]
+#[repr(usize)]
+enum SparseEnum {
+    Unit,
+    Other = 154,
+    Next,
+}
+
+#[test]
+fn can_round_trip_simple_enum() {
+    assert_eq!(SimpleEnum::try_from(0i64), Ok(SimpleEnum::A));
+    assert_eq!(SimpleEnum::try_from(1i64), Ok(SimpleEnum::B));
+    assert_eq!(SimpleEnum::try_from(2i64), Ok(SimpleEnum::C));
+}
+
+#[test]
+fn rejects_values_without_a_matching_discriminant() {
+    let err = SimpleEnum::try_from(3i64).unwrap_err();
+    assert_eq!(err.value, 3i64);
+}
+
+#[test]
+fn can_round_trip_sparse_enum() {
+    assert_eq!(SparseEnum::try_from(0usize), Ok(SparseEnum::Unit));
+    assert_eq!(SparseEnum::try_from(154usize), Ok(SparseEnum::Other));
+    assert_eq!(SparseEnum::try_from(155usize), Ok(SparseEnum::Next));
+    assert!(SparseEnum::try_from(1usize).is_err());
+}